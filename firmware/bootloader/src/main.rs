@@ -0,0 +1,30 @@
+// Second-stage bootloader for anchors/tags.
+//
+// Reads the state partition `updater` writes on a completed, verified
+// update, swaps the DFU bank into the active bank when a swap is pending,
+// and jumps into the active application. Image authenticity is the
+// application's job (see `updater::FirmwareUpdater::verify`) — by the time
+// a swap is pending here, the signature has already been checked.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use panic_probe as _;
+
+use embassy_boot_nrf::{BootLoader, BootLoaderConfig};
+use embassy_nrf::nvmc::Nvmc;
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+  let p = embassy_nrf::init(Default::default());
+  let flash = Nvmc::new(p.NVMC);
+
+  // Partition layout (active/dfu/state) comes from the linker script shared
+  // with `updater`, same as a stock embassy-boot setup.
+  let config = BootLoaderConfig::from_linkerfile_blocking(&flash, &flash, &flash);
+  let active_offset = config.active.offset();
+  let bootloader = BootLoader::prepare::<_, _, _, 2048>(config);
+
+  unsafe { bootloader.load(active_offset) }
+}
@@ -0,0 +1,168 @@
+// Optional frame-authentication layer for ranging frames, built on Ed25519
+// via `salty` (a no_std, no-heap implementation — the same one used for the
+// signed firmware-update path).
+//
+// Any radio that knows the over-the-air format can forge a tag's sequence
+// byte, so an anchor has no way to tell a real tag from an impersonator.
+// This layers a signature over `[tag_id][seq][timestamp]`: tags sign the
+// header with their private key and append the 64-byte signature, and
+// anchors verify it against a set of provisioned public keys before trusting
+// the frame.
+//
+// Feature-gated behind `auth` — deployments that don't need it don't pay for
+// pulling in the Ed25519 implementation or the verification path.
+
+use embedded_hal_async::digital::Wait;
+use embedded_hal_async::spi::SpiDevice;
+
+use crate::chip::Chip;
+use crate::driver::{Ready, Transceiver};
+use crate::error::Error;
+
+/// Length of the tag-id field in the signed header.
+const TAG_ID_LEN: usize = 2;
+/// Length of the sequence-number field in the signed header.
+const SEQ_LEN: usize = 1;
+/// Length of the timestamp field in the signed header.
+const TIMESTAMP_LEN: usize = 4;
+
+/// Length of `[tag_id][seq][timestamp]` — the bytes that get signed.
+pub const HEADER_LEN: usize = TAG_ID_LEN + SEQ_LEN + TIMESTAMP_LEN;
+/// Length of an Ed25519 signature.
+pub const SIGNATURE_LEN: usize = 64;
+/// Total length of an authenticated frame: header + signature.
+pub const FRAME_LEN: usize = HEADER_LEN + SIGNATURE_LEN;
+
+/// An Ed25519 keypair a tag uses to sign its outgoing ranging frames.
+pub struct Keypair(salty::Keypair);
+
+impl Keypair {
+  /// Derive a keypair from a 32-byte seed (e.g. provisioned at flash time).
+  pub fn from_seed(seed: &[u8; 32]) -> Self {
+    Self(salty::Keypair::from(seed))
+  }
+
+  /// The public key anchors must be provisioned with to verify this tag.
+  pub fn public(&self) -> PublicKey {
+    PublicKey(self.0.public)
+  }
+}
+
+/// An Ed25519 public key an anchor uses to verify a tag's frames.
+///
+/// `Copy` so a fixed set of provisioned keys can live in a `const` table and
+/// be passed to `recv_authenticated` by slice.
+#[derive(Clone, Copy)]
+pub struct PublicKey(salty::PublicKey);
+
+impl PublicKey {
+  /// Wrap a raw 32-byte public key, e.g. one burned into a compile-time table.
+  pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+    Self(salty::PublicKey::from_bytes(bytes))
+  }
+}
+
+/// A verified `[tag_id][seq][timestamp]` header from an authenticated frame.
+#[derive(Debug, Clone, Copy)]
+pub struct AuthenticatedHeader {
+  pub tag_id: u16,
+  pub seq: u8,
+  pub timestamp: u32,
+}
+
+fn encode_header(tag_id: u16, seq: u8, timestamp: u32) -> [u8; HEADER_LEN] {
+  let mut buf = [0u8; HEADER_LEN];
+  buf[0..2].copy_from_slice(&tag_id.to_le_bytes());
+  buf[2] = seq;
+  buf[3..7].copy_from_slice(&timestamp.to_le_bytes());
+  buf
+}
+
+fn decode_header(buf: &[u8]) -> AuthenticatedHeader {
+  let tag_id = u16::from_le_bytes([buf[0], buf[1]]);
+  let seq = buf[2];
+  let timestamp = u32::from_le_bytes([buf[3], buf[4], buf[5], buf[6]]);
+  AuthenticatedHeader { tag_id, seq, timestamp }
+}
+
+impl<SPI: SpiDevice, CHIP: Chip, IRQ: Wait> Transceiver<SPI, CHIP, Ready, IRQ> {
+  /// Sign `[tag_id][seq][timestamp]` with `keypair` and transmit the result
+  /// as a `FRAME_LEN`-byte frame (header followed by the 64-byte signature).
+  pub async fn send_authenticated(
+    &mut self,
+    keypair: &Keypair,
+    tag_id: u16,
+    seq: u8,
+    timestamp: u32,
+  ) -> Result<(), Error<SPI::Error>> {
+    let header = encode_header(tag_id, seq, timestamp);
+    let signature = keypair.0.sign(&header);
+
+    let mut frame = [0u8; FRAME_LEN];
+    frame[..HEADER_LEN].copy_from_slice(&header);
+    frame[HEADER_LEN..].copy_from_slice(&signature.to_bytes());
+
+    self.send(&frame).await
+  }
+
+  /// Receive a frame and verify its signature against `trusted_keys`.
+  ///
+  /// Tries each key in turn — there's no key-selection hint on the wire
+  /// beyond `tag_id`, which callers can use to narrow `trusted_keys` before
+  /// calling this if they keep a `tag_id -> PublicKey` table. Returns
+  /// `Error::AuthFailed` if the frame is shorter than `FRAME_LEN` or no key
+  /// verifies it.
+  pub async fn recv_authenticated(
+    &mut self,
+    trusted_keys: &[PublicKey],
+    max_polls: u32,
+  ) -> Result<AuthenticatedHeader, Error<SPI::Error>> {
+    let mut frame = [0u8; FRAME_LEN];
+    let read_len = self.recv(&mut frame, max_polls).await?;
+
+    if read_len < FRAME_LEN {
+      return Err(Error::AuthFailed);
+    }
+
+    let header = &frame[..HEADER_LEN];
+    let signature = salty::Signature::try_from(&frame[HEADER_LEN..FRAME_LEN]).map_err(|_| Error::AuthFailed)?;
+
+    let verified = trusted_keys.iter().any(|key| key.0.verify(header, &signature).is_ok());
+    if !verified {
+      return Err(Error::AuthFailed);
+    }
+
+    Ok(decode_header(header))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn header_round_trips_through_encoding() {
+    let header = encode_header(0xBEEF, 42, 0xDEAD_BEEF);
+    let decoded = decode_header(&header);
+    assert_eq!(decoded.tag_id, 0xBEEF);
+    assert_eq!(decoded.seq, 42);
+    assert_eq!(decoded.timestamp, 0xDEAD_BEEF);
+  }
+
+  #[test]
+  fn signature_verifies_against_matching_key_and_rejects_tamper() {
+    let keypair = Keypair::from_seed(&[7u8; 32]);
+    let public = keypair.public();
+
+    let header = encode_header(1, 2, 3);
+    let signature = keypair.0.sign(&header);
+    assert!(public.0.verify(&header, &signature).is_ok());
+
+    let mut tampered = header;
+    tampered[0] ^= 0xFF;
+    assert!(public.0.verify(&tampered, &signature).is_err());
+
+    let other = Keypair::from_seed(&[9u8; 32]).public();
+    assert!(other.0.verify(&header, &signature).is_err());
+  }
+}
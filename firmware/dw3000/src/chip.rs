@@ -0,0 +1,95 @@
+// Device-specific constants for DW3000-family UWB transceivers, factored
+// out behind a sealed `Chip` trait so the driver and its high-level API
+// (see `driver` and `ranging`) work unmodified across parts whose DEV_ID,
+// SYS_STATUS bit layout, or buffer base addresses differ.
+//
+// This mirrors how the W5500 Ethernet driver splits chip specifics (socket
+// register base addresses, buffer sizing) behind a trait instead of baking
+// one part's memory map into the driver.
+//
+// Scope note: the `Chip` trait exists so this driver can serve the whole DW
+// family, but DW1000 support is explicitly descoped from this change — only
+// `Dw3000Chip` ships. Earlier drafts included a `Dw1000Chip` with guessed
+// SYS_STATUS bit positions, RX_FINFO width, and buffer addresses; those
+// weren't checked against real hardware or the DW1000 User Manual and were
+// dropped rather than landed, since a silently-wrong chip impl is worse than
+// the driver simply not supporting the DW1000 yet. Adding `Dw1000Chip` here
+// is the natural next step, but it needs someone with DW1000 hardware (or
+// manual access) to verify its constants first.
+
+use crate::registers::Register;
+
+mod sealed {
+  pub trait Sealed {}
+}
+
+/// Constants that differ between members of the DW3000 family of chips.
+///
+/// Sealed — only this crate defines chips, so adding associated consts here
+/// isn't a breaking change for downstream users of `Transceiver`.
+pub trait Chip: sealed::Sealed {
+  /// Expected DEV_ID value, used to sanity-check SPI wiring in `init()`.
+  const DEV_ID: u32;
+
+  // SYS_STATUS bit layout.
+  const SYS_STATUS_TXFRS: u32;
+  const SYS_STATUS_RXDFR: u32;
+  const SYS_STATUS_RXFCG: u32;
+  const SYS_STATUS_RXFCE: u32;
+  const SYS_STATUS_RXPHE: u32;
+  const SYS_STATUS_RXPTO: u32;
+  const SYS_STATUS_RXSFDTO: u32;
+  /// High Priority Delayed Warning — set instead of `SYS_STATUS_TXFRS` firing
+  /// on schedule when a delayed TX (`CMD_DTX`, see `crate::ranging`) arrives
+  /// too late to honor its programmed `DX_TIME`.
+  const SYS_STATUS_HPDWARN: u32;
+
+  /// Mask for the frame-length field within RX_FINFO.
+  const RX_FINFO_RXFLEN_MASK: u32;
+
+  /// Transmit data buffer (write-only). Up to 1024 bytes.
+  const TX_BUFFER: Register;
+  /// Receive data buffer (read-only). Up to 1024 bytes.
+  const RX_BUFFER: Register;
+
+  /// SYS_CFG bit that disables double-buffered RX (cleared to enable it).
+  const SYS_CFG_DIS_DRXB: u32;
+  /// SYS_STATUS bit: which buffer the IC last wrote a frame into.
+  const SYS_STATUS_ICRBP: u32;
+  /// SYS_STATUS bit: which buffer the host currently considers "its turn" to read.
+  /// A new frame is ready whenever this differs from `SYS_STATUS_ICRBP`.
+  const SYS_STATUS_HSRBP: u32;
+  /// Receive buffer 0, aliased alongside `RX_BUFFER` for double-buffered RX.
+  const RX_BUFFER_0: Register;
+  /// Receive buffer 1, aliased alongside `RX_BUFFER` for double-buffered RX.
+  const RX_BUFFER_1: Register;
+}
+
+/// Qorvo/Decawave DW3000.
+pub struct Dw3000Chip;
+
+impl sealed::Sealed for Dw3000Chip {}
+
+impl Chip for Dw3000Chip {
+  const DEV_ID: u32 = 0xDECA0302; // DW3000 User Manual s8.1 — model 0x03, version/revision 02
+
+  const SYS_STATUS_TXFRS: u32 = 1 << 7; // TX frame sent
+  const SYS_STATUS_RXDFR: u32 = 1 << 13; // RX data frame ready
+  const SYS_STATUS_RXFCG: u32 = 1 << 14; // RX FCS good
+  const SYS_STATUS_RXFCE: u32 = 1 << 15; // RX FCS error
+  const SYS_STATUS_RXPHE: u32 = 1 << 12; // RX PHY header error
+  const SYS_STATUS_RXPTO: u32 = 1 << 21; // RX preamble detection timeout
+  const SYS_STATUS_RXSFDTO: u32 = 1 << 26; // RX SFD timeout
+  const SYS_STATUS_HPDWARN: u32 = 1 << 19; // High priority delayed warning
+
+  const RX_FINFO_RXFLEN_MASK: u32 = 0x3FF; // 10-bit frame length field
+
+  const TX_BUFFER: Register = Register { id: 0x14, sub: 0x00, len: 1024 };
+  const RX_BUFFER: Register = Register { id: 0x12, sub: 0x00, len: 1024 };
+
+  const SYS_CFG_DIS_DRXB: u32 = 1 << 12;
+  const SYS_STATUS_ICRBP: u32 = 1 << 29;
+  const SYS_STATUS_HSRBP: u32 = 1 << 30;
+  const RX_BUFFER_0: Register = Register { id: 0x12, sub: 0x00, len: 1024 };
+  const RX_BUFFER_1: Register = Register { id: 0x13, sub: 0x00, len: 1024 };
+}
@@ -7,14 +7,27 @@
 //                               +--receive()--> Receiving --wait_recv()----+
 //
 // Any active state (Sending/Receiving) can call force_idle() to abort back to Ready.
+//
+// Generic over `CHIP: Chip` (see `crate::chip`) so the same driver and
+// high-level API serve the DW3000 and any future chip in the family with a
+// verified `Chip` impl.
+
+use core::marker::PhantomData;
 
 use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital::Wait;
 use embedded_hal_async::spi::SpiDevice;
 
+use crate::chip::{Chip, Dw3000Chip};
 use crate::error::Error;
 use crate::ll;
 use crate::registers;
 
+/// Minimum payload size before a received frame is worth reading via
+/// `ll::read_reg_burst`'s full-duplex transfer instead of `ll::read_reg`'s
+/// plain write-then-read. See `Transceiver::read_rx_payload`.
+const BURST_READ_THRESHOLD: usize = 64;
+
 // ── Type-state markers (zero-size) ──────────────────────────────────────────
 
 pub struct Uninitialized;
@@ -22,42 +35,104 @@ pub struct Ready;
 pub struct Sending;
 pub struct Receiving;
 
+/// Placeholder "no IRQ pin" type for platforms that don't wire up the
+/// radio's interrupt line. Its `Wait` impl is never exercised — the driver
+/// only calls into `IRQ` when an actual pin has been supplied via
+/// `new_with_irq`/`init_with_irq` — it exists purely so `Transceiver` has a
+/// concrete default and callers that don't care about interrupts don't need
+/// to name a type.
+pub struct NoIrq;
+
+impl embedded_hal_async::digital::ErrorType for NoIrq {
+  type Error = core::convert::Infallible;
+}
+
+impl Wait for NoIrq {
+  async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+    Ok(())
+  }
+  async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+    Ok(())
+  }
+  async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+    Ok(())
+  }
+  async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+    Ok(())
+  }
+  async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+    Ok(())
+  }
+}
+
 // ── Driver struct ───────────────────────────────────────────────────────────
 
-/// DW3000-family UWB transceiver driver.
+/// UWB transceiver driver for any chip implementing `Chip`.
 ///
 /// Generic over:
 /// - `SPI`: any `embedded_hal_async::spi::SpiDevice` implementation
+/// - `CHIP`: the radio in use (`Dw3000Chip`, or another `Chip` impl once
+///   one has been verified against its datasheet) — supplies DEV_ID,
+///   SYS_STATUS bit layout, and buffer base addresses
 /// - `STATE`: compile-time state tracking (Uninitialized, Ready, Sending, Receiving)
-pub struct DW3000<SPI, STATE> {
-  spi: SPI,
+/// - `IRQ`: an `embedded_hal_async::digital::Wait` pin wired to the chip's
+///   IRQ line, or `NoIrq` (the default) if none is available. When `irq` is
+///   `None` — whether because the platform has no pin or because `IRQ =
+///   NoIrq` — TX/RX fall back to polling SYS_STATUS.
+pub struct Transceiver<SPI, CHIP, STATE, IRQ = NoIrq> {
+  pub(crate) spi: SPI,
+  irq: Option<IRQ>,
+  /// Which hardware RX buffer (0 or 1) the host currently expects a fresh
+  /// frame in, tracked for `recv_continuous()`'s double-buffered RX mode.
+  rx_buf_idx: u8,
+  _chip: PhantomData<CHIP>,
   _state: STATE,
 }
 
-// Transition helper — moves the SPI peripheral into a new state without copying.
-impl<SPI, STATE> DW3000<SPI, STATE> {
-  fn into_state<S>(self, state: S) -> DW3000<SPI, S> {
-    DW3000 { spi: self.spi, _state: state }
+/// Alias for the common case — a DW3000 transceiver. Use `Transceiver`
+/// directly to drive another `Chip` implementation.
+pub type DW3000<SPI, STATE, IRQ = NoIrq> = Transceiver<SPI, Dw3000Chip, STATE, IRQ>;
+
+// Transition helper — moves the SPI peripheral (and IRQ pin, if any) into a new state without copying.
+impl<SPI, CHIP, STATE, IRQ> Transceiver<SPI, CHIP, STATE, IRQ> {
+  fn into_state<S>(self, state: S) -> Transceiver<SPI, CHIP, S, IRQ> {
+    Transceiver { spi: self.spi, irq: self.irq, rx_buf_idx: self.rx_buf_idx, _chip: PhantomData, _state: state }
   }
 }
 
 // ── Uninitialized ───────────────────────────────────────────────────────────
 
-impl<SPI: SpiDevice> DW3000<SPI, Uninitialized> {
+impl<SPI: SpiDevice, CHIP: Chip> Transceiver<SPI, CHIP, Uninitialized, NoIrq> {
   /// Wrap an SPI device. The chip is assumed to be powered but not yet configured.
+  ///
+  /// TX/RX will busy-poll SYS_STATUS. Use `new_with_irq` if the radio's IRQ
+  /// line is wired to a GPIO so the driver can sleep between frames instead.
   pub fn new(spi: SPI) -> Self {
-    DW3000 { spi, _state: Uninitialized }
+    Transceiver { spi, irq: None, rx_buf_idx: 0, _chip: PhantomData, _state: Uninitialized }
   }
+}
 
-  /// Initialize the DW3000.
+impl<SPI: SpiDevice, CHIP: Chip, IRQ: Wait> Transceiver<SPI, CHIP, Uninitialized, IRQ> {
+  /// Wrap an SPI device together with a GPIO wired to the radio's IRQ line.
+  ///
+  /// TX/RX will enable the relevant SYS_STATUS bits and `await` a rising
+  /// edge on `irq` instead of polling.
+  pub fn new_with_irq(spi: SPI, irq: IRQ) -> Self {
+    Transceiver { spi, irq: Some(irq), rx_buf_idx: 0, _chip: PhantomData, _state: Uninitialized }
+  }
+}
+
+impl<SPI: SpiDevice, CHIP: Chip, IRQ> Transceiver<SPI, CHIP, Uninitialized, IRQ> {
+  /// Initialize the radio.
   ///
   /// 1. Sends TXRXOFF fast command to ensure idle state
   /// 2. Reads DEV_ID to verify SPI communication
   /// 3. Logs the device ID via defmt
   ///
-  /// Returns `Ready` state on success, or `UnexpectedDeviceId` if DEV_ID reads as 0 or 0xFFFFFFFF
-  /// (which indicates SPI wiring or clock issues).
-  pub async fn init(mut self, delay: &mut impl DelayNs) -> Result<DW3000<SPI, Ready>, Error<SPI::Error>> {
+  /// Returns `Ready` state on success, or `UnexpectedDeviceId` if DEV_ID
+  /// doesn't match `CHIP::DEV_ID`, or reads as 0 or 0xFFFFFFFF (which
+  /// indicates SPI wiring or clock issues).
+  pub async fn init(mut self, delay: &mut impl DelayNs) -> Result<Transceiver<SPI, CHIP, Ready, IRQ>, Error<SPI::Error>> {
     // Small delay after power-up to let the chip stabilize
     delay.delay_ms(5).await;
 
@@ -67,10 +142,11 @@ impl<SPI: SpiDevice> DW3000<SPI, Uninitialized> {
 
     // Read device ID to verify SPI link
     let dev_id = ll::read_reg_u32(&mut self.spi, registers::DEV_ID).await?;
-    defmt::info!("DW3000 DEV_ID: {:#010X}", dev_id);
+    defmt::info!("DEV_ID: {:#010X}", dev_id);
 
-    // Sanity check — 0x00000000 or 0xFFFFFFFF means SPI isn't working
-    if dev_id == 0x00000000 || dev_id == 0xFFFFFFFF {
+    // Sanity check — 0x00000000/0xFFFFFFFF means SPI isn't working, anything
+    // else must match the chip we were built for.
+    if dev_id == 0x00000000 || dev_id == 0xFFFFFFFF || dev_id != CHIP::DEV_ID {
       return Err(Error::UnexpectedDeviceId(dev_id));
     }
 
@@ -83,75 +159,143 @@ impl<SPI: SpiDevice> DW3000<SPI, Uninitialized> {
 
 // ── Ready ───────────────────────────────────────────────────────────────────
 
-impl<SPI: SpiDevice> DW3000<SPI, Ready> {
-  /// Transmit a frame.
-  ///
-  /// 1. Writes `data` into TX_BUFFER
-  /// 2. Sets TX_FCTRL with the frame length
-  /// 3. Issues CMD_TX fast command
-  ///
-  /// Transitions to `Sending` state. Call `wait_sent()` to block until transmission completes.
-  pub async fn send(&mut self, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+impl<SPI: SpiDevice, CHIP: Chip, IRQ: Wait> Transceiver<SPI, CHIP, Ready, IRQ> {
+  /// Write a payload into TX_BUFFER/TX_FCTRL and kick off transmission with
+  /// `cmd` (`CMD_TX` for immediate, `CMD_DTX` for delayed — see the `ranging`
+  /// module). Waits for TXFRS via the IRQ pin if one was supplied, otherwise
+  /// polls SYS_STATUS.
+  pub(crate) async fn transmit(&mut self, data: &[u8], cmd: u8) -> Result<(), Error<SPI::Error>> {
     // Write payload to TX buffer
-    ll::write_reg(&mut self.spi, registers::TX_BUFFER, data).await?;
+    ll::write_reg(&mut self.spi, CHIP::TX_BUFFER, data).await?;
 
     // Set frame length in TX_FCTRL (bits 0–9 = frame length including 2-byte FCS added by hardware)
-    let frame_len = (data.len() + 2) as u32; // +2 for the FCS the DW3000 appends
+    let frame_len = (data.len() + 2) as u32; // +2 for the FCS the radio appends
     let tx_fctrl = frame_len & 0x3FF;
     ll::write_reg_u32(&mut self.spi, registers::TX_FCTRL, tx_fctrl).await?;
 
-    // Start transmission
-    ll::fast_command(&mut self.spi, registers::CMD_TX).await?;
+    if let Some(irq) = self.irq.as_mut() {
+      // Unmask TXFRS, start transmission, then sleep until the IRQ line fires.
+      ll::write_reg_u32(&mut self.spi, registers::SYS_ENABLE, CHIP::SYS_STATUS_TXFRS).await?;
+      ll::fast_command(&mut self.spi, cmd).await?;
+      irq.wait_for_rising_edge().await.map_err(|_| Error::Timeout)?;
 
-    // Poll for TX complete
+      let status = ll::read_reg_u32(&mut self.spi, registers::SYS_STATUS).await?;
+      ll::write_reg_u32(&mut self.spi, registers::SYS_ENABLE, 0).await?;
+      return if status & CHIP::SYS_STATUS_TXFRS != 0 {
+        ll::write_reg_u32(&mut self.spi, registers::SYS_STATUS, CHIP::SYS_STATUS_TXFRS).await?;
+        Ok(())
+      } else {
+        Err(Error::Timeout)
+      };
+    }
+
+    // No IRQ pin — fall back to polling.
+    ll::fast_command(&mut self.spi, cmd).await?;
     for _ in 0..10_000u32 {
       let status = ll::read_reg_u32(&mut self.spi, registers::SYS_STATUS).await?;
-      if status & registers::SYS_STATUS_TXFRS != 0 {
+      if status & CHIP::SYS_STATUS_TXFRS != 0 {
         // Clear the TX done flag
-        ll::write_reg_u32(&mut self.spi, registers::SYS_STATUS, registers::SYS_STATUS_TXFRS).await?;
+        ll::write_reg_u32(&mut self.spi, registers::SYS_STATUS, CHIP::SYS_STATUS_TXFRS).await?;
         return Ok(());
       }
     }
     Err(Error::Timeout)
   }
 
+  /// Transmit a frame immediately.
+  ///
+  /// 1. Writes `data` into TX_BUFFER
+  /// 2. Sets TX_FCTRL with the frame length
+  /// 3. Issues CMD_TX fast command
+  ///
+  /// Returns once the frame has been sent (TXFRS seen via the IRQ pin or by
+  /// polling SYS_STATUS), or `Timeout` if TXFRS never sets.
+  pub async fn send(&mut self, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+    self.transmit(data, registers::CMD_TX).await
+  }
+
+  /// Read a frame payload from `reg` (an RX buffer), picking `ll::read_reg`
+  /// or `ll::read_reg_burst` by size.
+  ///
+  /// Payloads of at least `BURST_READ_THRESHOLD` bytes go through
+  /// `read_reg_burst`'s single full-duplex transfer; shorter ones (most
+  /// ranging frames) use `read_reg`. Both branches live in this async fn's
+  /// state, so its future reserves `read_reg_burst`'s two ~1KB scratch
+  /// buffers whichever branch runs.
+  async fn read_rx_payload(&mut self, reg: registers::Register, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+    if buf.len() >= BURST_READ_THRESHOLD {
+      ll::read_reg_burst(&mut self.spi, reg, buf).await
+    } else {
+      ll::read_reg(&mut self.spi, reg, buf).await
+    }
+  }
+
+  /// Inspect SYS_STATUS after CMD_RX has already been issued and the status
+  /// bits are known to be settled (either because a poll saw them set, or
+  /// because the IRQ line fired). Reads the frame from RX_BUFFER on a good
+  /// FCS, or clears and re-arms the receiver on an RX error.
+  async fn handle_rx_status(&mut self, buf: &mut [u8], status: u32) -> Result<Option<usize>, Error<SPI::Error>> {
+    if status & CHIP::SYS_STATUS_RXFCG != 0 {
+      // Good frame received — read its length from RX_FINFO
+      let finfo = ll::read_reg_u32(&mut self.spi, registers::RX_FINFO).await?;
+      let frame_len = (finfo & CHIP::RX_FINFO_RXFLEN_MASK) as usize;
+
+      // Subtract the 2-byte FCS to get payload length
+      let payload_len = if frame_len >= 2 { frame_len - 2 } else { 0 };
+      let read_len = payload_len.min(buf.len());
+
+      // Read payload from RX buffer
+      self.read_rx_payload(CHIP::RX_BUFFER, &mut buf[..read_len]).await?;
+
+      // Clear RX status flags
+      let clear_mask = CHIP::SYS_STATUS_RXDFR | CHIP::SYS_STATUS_RXFCG;
+      ll::write_reg_u32(&mut self.spi, registers::SYS_STATUS, clear_mask).await?;
+
+      return Ok(Some(read_len));
+    }
+
+    // Check for RX errors — clear and re-enable receiver
+    let rx_err = CHIP::SYS_STATUS_RXFCE | CHIP::SYS_STATUS_RXPHE;
+    if status & rx_err != 0 {
+      ll::write_reg_u32(&mut self.spi, registers::SYS_STATUS, rx_err).await?;
+      ll::fast_command(&mut self.spi, registers::CMD_RX).await?;
+    }
+
+    Ok(None)
+  }
+
   /// Enable the receiver and wait for a frame.
   ///
-  /// Issues CMD_RX, then polls until a frame with good FCS arrives.
+  /// Issues CMD_RX, then waits for a frame with good FCS — via the IRQ pin
+  /// if one was supplied, otherwise by polling SYS_STATUS.
   /// Reads the frame from RX_BUFFER into `buf` and returns the number of payload bytes.
   ///
-  /// `max_polls`: maximum number of status reads before returning `Timeout`.
+  /// `max_polls`: maximum number of status reads before returning `Timeout`
+  /// (ignored when an IRQ pin is in use — a single edge wait stands in for the whole loop).
   pub async fn recv(&mut self, buf: &mut [u8], max_polls: u32) -> Result<usize, Error<SPI::Error>> {
     ll::fast_command(&mut self.spi, registers::CMD_RX).await?;
 
-    for _ in 0..max_polls {
-      let status = ll::read_reg_u32(&mut self.spi, registers::SYS_STATUS).await?;
-
-      if status & registers::SYS_STATUS_RXFCG != 0 {
-        // Good frame received — read its length from RX_FINFO
-        let finfo = ll::read_reg_u32(&mut self.spi, registers::RX_FINFO).await?;
-        let frame_len = (finfo & registers::RX_FINFO_RXFLEN_MASK) as usize;
+    if let Some(irq) = self.irq.as_mut() {
+      let enable_mask = CHIP::SYS_STATUS_RXFCG | CHIP::SYS_STATUS_RXFCE | CHIP::SYS_STATUS_RXPHE;
+      ll::write_reg_u32(&mut self.spi, registers::SYS_ENABLE, enable_mask).await?;
+      irq.wait_for_rising_edge().await.map_err(|_| Error::Timeout)?;
 
-        // Subtract the 2-byte FCS to get payload length
-        let payload_len = if frame_len >= 2 { frame_len - 2 } else { 0 };
-        let read_len = payload_len.min(buf.len());
-
-        // Read payload from RX buffer
-        ll::read_reg(&mut self.spi, registers::RX_BUFFER, &mut buf[..read_len]).await?;
-
-        // Clear RX status flags
-        let clear_mask = registers::SYS_STATUS_RXDFR | registers::SYS_STATUS_RXFCG;
-        ll::write_reg_u32(&mut self.spi, registers::SYS_STATUS, clear_mask).await?;
+      let status = ll::read_reg_u32(&mut self.spi, registers::SYS_STATUS).await?;
+      ll::write_reg_u32(&mut self.spi, registers::SYS_ENABLE, 0).await?;
+      return match self.handle_rx_status(buf, status).await? {
+        Some(read_len) => Ok(read_len),
+        None => {
+          ll::fast_command(&mut self.spi, registers::CMD_TXRXOFF).await?;
+          Err(Error::Timeout)
+        }
+      };
+    }
 
+    for _ in 0..max_polls {
+      let status = ll::read_reg_u32(&mut self.spi, registers::SYS_STATUS).await?;
+      if let Some(read_len) = self.handle_rx_status(buf, status).await? {
         return Ok(read_len);
       }
-
-      // Check for RX errors — clear and re-enable receiver
-      let rx_err = registers::SYS_STATUS_RXFCE | registers::SYS_STATUS_RXPHE;
-      if status & rx_err != 0 {
-        ll::write_reg_u32(&mut self.spi, registers::SYS_STATUS, rx_err).await?;
-        ll::fast_command(&mut self.spi, registers::CMD_RX).await?;
-      }
     }
 
     // Timed out — return to idle
@@ -164,4 +308,64 @@ impl<SPI: SpiDevice> DW3000<SPI, Ready> {
   pub async fn force_idle(&mut self) -> Result<(), Error<SPI::Error>> {
     ll::fast_command(&mut self.spi, registers::CMD_TXRXOFF).await
   }
+
+  /// Enable double-buffered receive mode, so a frame can land in the other
+  /// hardware RX buffer while the host is still reading the previous one out
+  /// — see `recv_continuous()`. Call once before the first `recv_continuous()`.
+  pub async fn enable_double_buffered_rx(&mut self) -> Result<(), Error<SPI::Error>> {
+    let mut sys_cfg = ll::read_reg_u32(&mut self.spi, registers::SYS_CFG).await?;
+    sys_cfg &= !CHIP::SYS_CFG_DIS_DRXB;
+    ll::write_reg_u32(&mut self.spi, registers::SYS_CFG, sys_cfg).await?;
+
+    self.rx_buf_idx = 0;
+    ll::fast_command(&mut self.spi, registers::CMD_DRX).await
+  }
+
+  /// Receive a frame in double-buffered mode.
+  ///
+  /// A frame is ready once SYS_STATUS's IC-side buffer pointer (`ICRBP`)
+  /// differs from the host-side one (`HSRBP`). Reads the just-finished
+  /// buffer (`RX_BUFFER_0`/`RX_BUFFER_1`, alternating each call) while the
+  /// radio keeps filling the other one, then toggles HSRBP (via SYS_CTRL's
+  /// HRBPT) to hand the read buffer back to the radio; no RX enable command
+  /// is issued here. `enable_double_buffered_rx()` must be called first.
+  pub async fn recv_continuous(&mut self, buf: &mut [u8], max_polls: u32) -> Result<usize, Error<SPI::Error>> {
+    for _ in 0..max_polls {
+      let status = ll::read_reg_u32(&mut self.spi, registers::SYS_STATUS).await?;
+
+      let icrbp = status & CHIP::SYS_STATUS_ICRBP != 0;
+      let hsrbp = status & CHIP::SYS_STATUS_HSRBP != 0;
+      if icrbp == hsrbp {
+        // Nothing new in the other buffer yet.
+        continue;
+      }
+
+      if status & CHIP::SYS_STATUS_RXFCG == 0 {
+        // Bad frame in the other buffer — clear the error and keep waiting.
+        let rx_err = CHIP::SYS_STATUS_RXFCE | CHIP::SYS_STATUS_RXPHE;
+        ll::write_reg_u32(&mut self.spi, registers::SYS_STATUS, rx_err).await?;
+        continue;
+      }
+
+      let active_buffer = if self.rx_buf_idx == 0 { CHIP::RX_BUFFER_0 } else { CHIP::RX_BUFFER_1 };
+
+      let finfo = ll::read_reg_u32(&mut self.spi, registers::RX_FINFO).await?;
+      let frame_len = (finfo & CHIP::RX_FINFO_RXFLEN_MASK) as usize;
+      let payload_len = if frame_len >= 2 { frame_len - 2 } else { 0 };
+      let read_len = payload_len.min(buf.len());
+
+      self.read_rx_payload(active_buffer, &mut buf[..read_len]).await?;
+
+      let clear_mask = CHIP::SYS_STATUS_RXDFR | CHIP::SYS_STATUS_RXFCG;
+      ll::write_reg_u32(&mut self.spi, registers::SYS_STATUS, clear_mask).await?;
+
+      // Hand the just-read buffer back to the radio and flip to the other one.
+      ll::write_reg_u32(&mut self.spi, registers::SYS_CTRL, registers::SYS_CTRL_HRBPT).await?;
+      self.rx_buf_idx ^= 1;
+
+      return Ok(read_len);
+    }
+
+    Err(Error::Timeout)
+  }
 }
@@ -9,6 +9,12 @@ pub enum Error<SPI> {
 
   /// A transmit or receive operation timed out (SYS_STATUS never set the expected flag).
   Timeout,
+
+  /// An authenticated frame's Ed25519 signature didn't verify against any
+  /// trusted public key, or the frame was too short to contain one. See
+  /// `crate::auth`.
+  #[cfg(feature = "auth")]
+  AuthFailed,
 }
 
 // Manual defmt::Format impl — we can't derive it because the SPI error type
@@ -20,6 +26,8 @@ impl<SPI: core::fmt::Debug> defmt::Format for Error<SPI> {
       Error::Spi(_) => defmt::write!(f, "SPI bus error"),
       Error::UnexpectedDeviceId(id) => defmt::write!(f, "Unexpected DEV_ID: {:#010X}", id),
       Error::Timeout => defmt::write!(f, "Timeout"),
+      #[cfg(feature = "auth")]
+      Error::AuthFailed => defmt::write!(f, "Frame authentication failed"),
     }
   }
 }
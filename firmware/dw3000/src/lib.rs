@@ -1,8 +1,14 @@
-#![no_std]
+// `std` is only needed for `#[cfg(test)]` unit tests, which run on the host
+// target rather than the embedded one.
+#![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "auth")]
+pub mod auth;
+pub mod chip;
 pub mod driver;
 pub mod error;
 pub mod ll;
+pub mod ranging;
 pub mod registers;
 
 pub use driver::*;
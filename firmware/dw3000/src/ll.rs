@@ -47,6 +47,38 @@ pub async fn read_reg<SPI: SpiDevice>(spi: &mut SPI, reg: Register, buf: &mut [u
     .map_err(Error::Spi)
 }
 
+/// Largest single-register read this layer can burst in one transfer —
+/// the DW3000 family's widest buffers (TX_BUFFER/RX_BUFFER) are 1024 bytes,
+/// see `registers::Register::len`, and no register in the family is wider.
+pub const MAX_BURST_LEN: usize = 1024;
+
+/// Read bytes from a register as a single full-duplex transfer, instead of
+/// `read_reg`'s separate write-then-read operations.
+///
+/// Clocks the header out and the data in at the same time via one
+/// `Operation::Transfer`, which is worthwhile for large reads (the
+/// 1024-byte RX_BUFFER/TX_BUFFER) on buses where each `Operation` in a
+/// transaction carries its own per-call overhead. `buf.len()` beyond
+/// `MAX_BURST_LEN` is truncated, same as the RX-length clamping `recv()`
+/// already does against its caller-supplied buffer.
+pub async fn read_reg_burst<SPI: SpiDevice>(spi: &mut SPI, reg: Register, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+  let len = buf.len().min(MAX_BURST_LEN);
+  let header = build_header(false, reg);
+
+  let mut write_buf = [0u8; 2 + MAX_BURST_LEN];
+  write_buf[..2].copy_from_slice(&header);
+
+  let mut read_buf = [0u8; 2 + MAX_BURST_LEN];
+
+  spi
+    .transaction(&mut [embedded_hal_async::spi::Operation::Transfer(&mut read_buf[..2 + len], &write_buf[..2 + len])])
+    .await
+    .map_err(Error::Spi)?;
+
+  buf[..len].copy_from_slice(&read_buf[2..2 + len]);
+  Ok(())
+}
+
 /// Write bytes to a register.
 ///
 /// Sends a 2-byte write header followed by the data bytes, all in one CS frame.
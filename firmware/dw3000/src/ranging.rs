@@ -0,0 +1,266 @@
+// Single-sided two-way ranging (SS-TWR) built on top of the TX_TIME/RX_TIME
+// timestamp registers and delayed transmission via DX_TIME.
+//
+// Protocol (initiator I, responder R):
+//
+//   I --poll-----> R        I stamps its TX at T1
+//                           R stamps the RX at T2, schedules a delayed reply
+//                           so its own TX lands at T3, and embeds (T3 − T2)
+//                           in the reply payload
+//   R --response-> I        I stamps the RX at T4
+//
+//   time-of-flight = ((T4 − T1) − (T3 − T2)) / 2
+//   distance       = time-of-flight * SPEED_OF_LIGHT
+//
+// Device time is a 40-bit counter ticking at ~15.65 ps (1 / (128 * 499.2 MHz))
+// that wraps at 2^40, so every subtraction below is wrapping arithmetic done
+// modulo 2^40.
+
+use embedded_hal_async::digital::Wait;
+use embedded_hal_async::spi::SpiDevice;
+
+use crate::chip::Chip;
+use crate::driver::{Ready, Transceiver};
+use crate::error::Error;
+use crate::ll;
+use crate::registers;
+
+/// Speed of light in a vacuum, in metres per second.
+pub const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+
+/// Device-time tick period, in seconds (1 / (128 * 499.2 MHz)).
+pub const DEVICE_TIME_UNIT: f64 = 1.0 / (128.0 * 499.2e6);
+
+/// Device time is a 40-bit counter; it wraps at 2^40.
+const DEVICE_TIME_MASK: u64 = (1 << 40) - 1;
+
+/// The DW3000 ignores the low 9 bits of a delayed-TX `DX_TIME` write — the
+/// frame's actual TX time is the programmed value with those bits cleared,
+/// not the value itself. Masking a planned time with this before using it
+/// for anything that must match what the radio actually does (e.g. the
+/// reply delay embedded in `ss_twr_responder`) keeps the two in sync
+/// without needing a post-transmit readback, which would be too late to
+/// embed in the very frame that already went out.
+const DX_TIME_COARSE_MASK: u64 = !0x1FF & DEVICE_TIME_MASK;
+
+/// How far into the future (in device-time ticks) a responder schedules its
+/// delayed reply — must leave enough headroom for the DX_TIME write, the
+/// TX_BUFFER/TX_FCTRL writes, and the CMD_DTX fast command to all clock out
+/// over SPI before the scheduled TX time arrives, or the delayed TX misses
+/// its deadline (HPDWARN) and never fires. ~131 us leaves several times
+/// that worst case's margin even at a conservative 8 MHz SPI clock.
+const RESPONSE_DELAY_TICKS: u64 = 1 << 23; // ~131 us
+
+/// Number of bytes used to embed a 40-bit reply-delay value in a frame.
+const REPLY_DELAY_LEN: usize = 5;
+
+/// Result of a completed single-sided two-way ranging exchange.
+#[derive(Debug, Clone, Copy)]
+pub struct RangingResult {
+  /// Time of flight, in device-time ticks.
+  pub tof_ticks: u64,
+  /// Estimated distance, in metres.
+  pub distance_m: f64,
+  /// Round-trip time at the initiator (`T4 − T1`), in device-time ticks.
+  /// Needed by `combine_double_sided`'s DS-TWR estimator; meaningless on a
+  /// result that's itself the output of `combine_double_sided`.
+  pub round_trip_ticks: u64,
+  /// Reply delay at the responder (`T3 − T2`), in device-time ticks. Needed
+  /// by `combine_double_sided`'s DS-TWR estimator; meaningless on a result
+  /// that's itself the output of `combine_double_sided`.
+  pub reply_delay_ticks: u64,
+}
+
+fn ticks_to_distance(tof_ticks: u64) -> f64 {
+  (tof_ticks as f64) * DEVICE_TIME_UNIT * SPEED_OF_LIGHT
+}
+
+/// Subtract two 40-bit device-time values, wrapping at 2^40.
+fn wrapping_sub_40(a: u64, b: u64) -> u64 {
+  a.wrapping_sub(b) & DEVICE_TIME_MASK
+}
+
+/// Encode a 40-bit tick count as little-endian bytes for embedding in a payload.
+fn encode_ticks(ticks: u64) -> [u8; REPLY_DELAY_LEN] {
+  let bytes = ticks.to_le_bytes();
+  [bytes[0], bytes[1], bytes[2], bytes[3], bytes[4]]
+}
+
+/// Decode a 40-bit tick count from its little-endian byte encoding.
+fn decode_ticks(bytes: &[u8]) -> u64 {
+  let mut buf = [0u8; 8];
+  buf[..REPLY_DELAY_LEN].copy_from_slice(&bytes[..REPLY_DELAY_LEN]);
+  u64::from_le_bytes(buf) & DEVICE_TIME_MASK
+}
+
+async fn read_tx_time<SPI: SpiDevice>(spi: &mut SPI) -> Result<u64, Error<SPI::Error>> {
+  let mut buf = [0u8; 8];
+  ll::read_reg(spi, registers::TX_TIME, &mut buf[..REPLY_DELAY_LEN]).await?;
+  Ok(u64::from_le_bytes(buf) & DEVICE_TIME_MASK)
+}
+
+async fn read_rx_time<SPI: SpiDevice>(spi: &mut SPI) -> Result<u64, Error<SPI::Error>> {
+  let mut buf = [0u8; 8];
+  ll::read_reg(spi, registers::RX_TIME, &mut buf[..REPLY_DELAY_LEN]).await?;
+  Ok(u64::from_le_bytes(buf) & DEVICE_TIME_MASK)
+}
+
+async fn write_dx_time<SPI: SpiDevice>(spi: &mut SPI, dx_time: u64) -> Result<(), Error<SPI::Error>> {
+  let bytes = dx_time.to_le_bytes();
+  ll::write_reg(spi, registers::DX_TIME, &bytes[..REPLY_DELAY_LEN]).await
+}
+
+impl<SPI: SpiDevice, CHIP: Chip, IRQ: Wait> Transceiver<SPI, CHIP, Ready, IRQ> {
+  /// Initiate single-sided two-way ranging against a responder.
+  ///
+  /// Sends `poll_payload`, then waits up to `max_polls` status reads for the
+  /// response frame. The response must carry the responder's `T3 − T2` reply
+  /// delay as the last 5 bytes of the frame (see `ss_twr_responder`).
+  pub async fn ss_twr_initiator(
+    &mut self,
+    poll_payload: &[u8],
+    resp_buf: &mut [u8],
+    max_polls: u32,
+  ) -> Result<RangingResult, Error<SPI::Error>> {
+    self.send(poll_payload).await?;
+    let t1 = read_tx_time(&mut self.spi).await?;
+
+    let read_len = self.recv(resp_buf, max_polls).await?;
+    let t4 = read_rx_time(&mut self.spi).await?;
+
+    if read_len < REPLY_DELAY_LEN {
+      return Err(Error::Timeout);
+    }
+    let reply_delay = decode_ticks(&resp_buf[read_len - REPLY_DELAY_LEN..read_len]);
+
+    let round_trip = wrapping_sub_40(t4, t1);
+    let tof_ticks = wrapping_sub_40(round_trip, reply_delay) / 2;
+
+    Ok(RangingResult {
+      tof_ticks,
+      distance_m: ticks_to_distance(tof_ticks),
+      round_trip_ticks: round_trip,
+      reply_delay_ticks: reply_delay,
+    })
+  }
+
+  /// Respond to a single-sided two-way ranging poll.
+  ///
+  /// Waits for the poll frame, then schedules a delayed reply (via DX_TIME
+  /// and CMD_DTX) so its own TX timestamp lands a known number of ticks
+  /// after the poll's RX timestamp, embedding that delay (`T3 − T2`) as the
+  /// last 5 bytes of `reply_payload`'s frame. The embedded delay is computed
+  /// against the coarse (`DX_TIME_COARSE_MASK`-ed) T3 the radio will
+  /// actually transmit at, not the unmasked planned value, since the two
+  /// can differ by up to ~511 ticks and that delta would otherwise show up
+  /// directly in the initiator's distance estimate.
+  ///
+  /// `reply_payload` plus the 5-byte delay must fit in `frame_scratch`.
+  pub async fn ss_twr_responder(
+    &mut self,
+    poll_buf: &mut [u8],
+    reply_payload: &[u8],
+    frame_scratch: &mut [u8],
+    max_polls: u32,
+  ) -> Result<usize, Error<SPI::Error>> {
+    let read_len = self.recv(poll_buf, max_polls).await?;
+    let t2 = read_rx_time(&mut self.spi).await?;
+
+    let t3_planned = (t2 + RESPONSE_DELAY_TICKS) & DEVICE_TIME_MASK;
+    write_dx_time(&mut self.spi, t3_planned).await?;
+
+    let t3_actual = t3_planned & DX_TIME_COARSE_MASK;
+    let reply_delay = wrapping_sub_40(t3_actual, t2);
+    let delay_bytes = encode_ticks(reply_delay);
+
+    let total_len = reply_payload.len() + delay_bytes.len();
+    frame_scratch[..reply_payload.len()].copy_from_slice(reply_payload);
+    frame_scratch[reply_payload.len()..total_len].copy_from_slice(&delay_bytes);
+
+    self.transmit(&frame_scratch[..total_len], registers::CMD_DTX).await?;
+
+    let status = ll::read_reg_u32(&mut self.spi, registers::SYS_STATUS).await?;
+    if status & CHIP::SYS_STATUS_HPDWARN != 0 {
+      // The delayed TX missed its DX_TIME deadline and never fired — clear
+      // the warning so it doesn't linger across the next exchange and tell
+      // the caller the reply never went out.
+      ll::write_reg_u32(&mut self.spi, registers::SYS_STATUS, CHIP::SYS_STATUS_HPDWARN).await?;
+      return Err(Error::Timeout);
+    }
+
+    Ok(read_len)
+  }
+}
+
+/// Combine a forward and reverse SS-TWR exchange into a double-sided
+/// (DS-TWR) time-of-flight estimate.
+///
+/// Naively averaging the two single-sided estimates does *not* cancel
+/// first-order crystal-offset error — that requires weighting by the
+/// round-trip and reply-delay intervals themselves. Given round trips
+/// `Ra`, `Rb` and reply delays `Da`, `Db` from the forward and reverse
+/// exchanges respectively, the standard DS-TWR estimator is:
+///
+///   tof = (Ra·Rb − Da·Db) / (Ra + Rb + Da + Db)
+///
+/// `forward` and `reverse` must be the direct outputs of
+/// `ss_twr_initiator` (i.e. carry real `round_trip_ticks`/`reply_delay_ticks`),
+/// not a previously combined result.
+pub fn combine_double_sided(forward: RangingResult, reverse: RangingResult) -> RangingResult {
+  let ra = forward.round_trip_ticks as f64;
+  let da = forward.reply_delay_ticks as f64;
+  let rb = reverse.round_trip_ticks as f64;
+  let db = reverse.reply_delay_ticks as f64;
+
+  let tof_ticks = ((ra * rb - da * db) / (ra + rb + da + db)) as u64;
+  RangingResult { tof_ticks, distance_m: ticks_to_distance(tof_ticks), round_trip_ticks: 0, reply_delay_ticks: 0 }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn wrapping_sub_handles_wraparound() {
+    assert_eq!(wrapping_sub_40(10, 3), 7);
+    assert_eq!(wrapping_sub_40(5, 10), DEVICE_TIME_MASK - 4);
+    assert_eq!(wrapping_sub_40(DEVICE_TIME_MASK, DEVICE_TIME_MASK), 0);
+  }
+
+  #[test]
+  fn ticks_round_trip_through_encoding() {
+    for ticks in [0u64, 1, 12345, DEVICE_TIME_MASK / 2, DEVICE_TIME_MASK] {
+      let encoded = encode_ticks(ticks);
+      assert_eq!(decode_ticks(&encoded), ticks);
+    }
+  }
+
+  #[test]
+  fn ticks_to_distance_matches_speed_of_light() {
+    // One tick (~15.65 ps) of flight at the speed of light is ~4.692 mm, and
+    // distance should scale linearly with tick count.
+    let one_tick = ticks_to_distance(1);
+    assert!((one_tick - 0.004_692).abs() < 1e-6);
+    let thousand_ticks = ticks_to_distance(1000);
+    assert!((thousand_ticks - one_tick * 1000.0).abs() < 1e-6);
+  }
+
+  #[test]
+  fn combine_double_sided_matches_symmetric_single_sided() {
+    // A symmetric exchange (forward and reverse see identical timing)
+    // should combine to the same time-of-flight as either leg alone.
+    let reply_delay = 1 << 23;
+    let round_trip = reply_delay + 1000; // a round trip always exceeds the reply delay it contains
+    let tof_ticks = wrapping_sub_40(round_trip, reply_delay) / 2;
+    let leg = RangingResult {
+      tof_ticks,
+      distance_m: ticks_to_distance(tof_ticks),
+      round_trip_ticks: round_trip,
+      reply_delay_ticks: reply_delay,
+    };
+
+    let combined = combine_double_sided(leg, leg);
+    let diff = (combined.tof_ticks as i64 - tof_ticks as i64).abs();
+    assert!(diff <= 1, "expected ~{tof_ticks}, got {}", combined.tof_ticks);
+  }
+}
@@ -24,43 +24,62 @@ pub const EUI_64: Register = Register { id: 0x00, sub: 0x04, len: 8 };
 
 // ── System configuration ─────────────────────────────────────────────────────
 
-/// System configuration register.
+/// System configuration register. The double-buffered-RX disable bit is
+/// chip-specific — see `Chip::SYS_CFG_DIS_DRXB`.
 pub const SYS_CFG: Register = Register { id: 0x00, sub: 0x10, len: 4 };
 
+// ── System control ───────────────────────────────────────────────────────────
+
+/// System control register — one-shot control bits.
+pub const SYS_CTRL: Register = Register { id: 0x00, sub: 0x0C, len: 4 };
+
+/// Host Receive Buffer Pointer Toggle — flips which hardware RX buffer the
+/// host considers current after it finishes reading a frame out in
+/// double-buffered mode.
+pub const SYS_CTRL_HRBPT: u32 = 1 << 1;
+
 // ── TX control ───────────────────────────────────────────────────────────────
 
 /// Transmit frame control — sets payload length, data rate, PRF, preamble length.
 pub const TX_FCTRL: Register = Register { id: 0x00, sub: 0x24, len: 4 };
 
+// ── Interrupt configuration ─────────────────────────────────────────────────
+
+/// Interrupt enable mask — bits mirror SYS_STATUS; setting a bit there lets
+/// that event assert the IRQ line.
+pub const SYS_ENABLE: Register = Register { id: 0x00, sub: 0x3C, len: 4 };
+
 // ── Status ───────────────────────────────────────────────────────────────────
 
 /// System status register — flags for TX done, RX done, errors, etc.
+/// The bit layout within it is chip-specific — see `crate::chip::Chip`.
 pub const SYS_STATUS: Register = Register { id: 0x00, sub: 0x44, len: 4 };
 
-// SYS_STATUS bit masks
-pub const SYS_STATUS_TXFRS: u32 = 1 << 7; // TX frame sent
-pub const SYS_STATUS_RXDFR: u32 = 1 << 13; // RX data frame ready
-pub const SYS_STATUS_RXFCG: u32 = 1 << 14; // RX FCS good
-pub const SYS_STATUS_RXFCE: u32 = 1 << 15; // RX FCS error
-pub const SYS_STATUS_RXPHE: u32 = 1 << 12; // RX PHY header error
-pub const SYS_STATUS_RXPTO: u32 = 1 << 21; // RX preamble detection timeout
-pub const SYS_STATUS_RXSFDTO: u32 = 1 << 26; // RX SFD timeout
-
 // ── RX info ──────────────────────────────────────────────────────────────────
 
 /// Receive frame info — frame length, ranging flag, etc.
+/// The width of the frame-length field is chip-specific — see `crate::chip::Chip`.
 pub const RX_FINFO: Register = Register { id: 0x00, sub: 0x4C, len: 4 };
 
-/// Mask for the frame length field within RX_FINFO (bits 0–9, 10-bit value).
-pub const RX_FINFO_RXFLEN_MASK: u32 = 0x3FF;
+// ── Timestamps ───────────────────────────────────────────────────────────────
+//
+// All three are 40-bit device-time values (low 5 bytes of the backing
+// register), ticking at ~15.65 ps and wrapping at 2^40.
 
-// ── Data buffers ─────────────────────────────────────────────────────────────
+/// Delayed TX/RX time — the device-time value at which a delayed transmit
+/// (CMD_DTX) or delayed receive-enable should fire.
+pub const DX_TIME: Register = Register { id: 0x00, sub: 0x54, len: 5 };
+
+/// Device-time value at which the last RX frame's first byte was detected.
+pub const RX_TIME: Register = Register { id: 0x00, sub: 0x64, len: 5 };
 
-/// Transmit data buffer (write-only). Up to 1024 bytes.
-pub const TX_BUFFER: Register = Register { id: 0x14, sub: 0x00, len: 1024 };
+/// Device-time value at which the last TX frame was actually transmitted.
+pub const TX_TIME: Register = Register { id: 0x00, sub: 0x74, len: 5 };
 
-/// Receive data buffer (read-only). Up to 1024 bytes.
-pub const RX_BUFFER: Register = Register { id: 0x12, sub: 0x00, len: 1024 };
+// ── Data buffers ─────────────────────────────────────────────────────────────
+//
+// Buffer base addresses are chip-specific — see `Chip::TX_BUFFER`/`Chip::RX_BUFFER`
+// and, for double-buffered RX, `Chip::RX_BUFFER_0`/`Chip::RX_BUFFER_1`.
 
 // ── Fast commands ────────────────────────────────────────────────────────────
 //
@@ -70,5 +89,6 @@ pub const RX_BUFFER: Register = Register { id: 0x12, sub: 0x00, len: 1024 };
 pub const CMD_TX: u8 = 0x01; // Start transmission
 pub const CMD_RX: u8 = 0x02; // Enable receiver
 pub const CMD_TXRXOFF: u8 = 0x03; // Abort TX/RX, return to idle
-pub const CMD_DRX: u8 = 0x04; // Double-buffered RX mode (delayed)
+pub const CMD_DRX: u8 = 0x04; // Enable receiver in double-buffered mode
+pub const CMD_DTX: u8 = 0x05; // Start delayed transmission, using DX_TIME as the TX time
 pub const CMD_CLR_IRQS: u8 = 0x0E; // Clear all interrupt flags
@@ -2,19 +2,40 @@ use embassy_nrf::gpio::Output;
 use embassy_nrf::spim::{self, Spim};
 use embedded_hal_async::spi::{self as hal_spi, Operation};
 
+/// Chip-select handling for `SpiDevice::transaction`.
+enum ChipSelect<'a> {
+  /// Assert/deassert a GPIO in software around each transaction — the
+  /// default, and the only option on nRF, whose SPIM peripheral doesn't
+  /// drive hardware CS.
+  Gpio(Output<'a>),
+  /// Skip CS handling entirely, for platforms whose SPI peripheral asserts
+  /// CS itself for the duration of a transaction (e.g. ESP-IDF's SPI
+  /// driver) — toggling a GPIO on top of that would just fight it.
+  Hardware,
+}
+
 /// SPI device wrapper that implements `embedded_hal_async::spi::SpiDevice`.
 ///
-/// Pairs an Embassy nRF SPIM peripheral with a CS (chip select) GPIO pin.
-/// The `transaction()` method asserts CS low, executes all operations, then
-/// deasserts CS — matching the contract that `SpiDevice` requires.
+/// Pairs an Embassy nRF SPIM peripheral with a chip-select strategy. The
+/// `transaction()` method asserts CS, executes all operations, then
+/// deasserts CS — matching the contract that `SpiDevice` requires — except
+/// when CS is hardware-controlled, in which case it's a no-op either way.
 pub struct SpiDevice<'a> {
   spi: Spim<'a>,
-  cs: Output<'a>,
+  cs: ChipSelect<'a>,
 }
 
 impl<'a> SpiDevice<'a> {
+  /// Software chip-select (the default) — toggles `cs` low/high in software
+  /// around each transaction.
   pub fn new(spi: Spim<'a>, cs: Output<'a>) -> Self {
-    Self { spi, cs }
+    Self { spi, cs: ChipSelect::Gpio(cs) }
+  }
+
+  /// Hardware chip-select — for platforms whose SPI peripheral drives CS
+  /// itself, so the driver doesn't need (and shouldn't own) a GPIO for it.
+  pub fn new_hardware_cs(spi: Spim<'a>) -> Self {
+    Self { spi, cs: ChipSelect::Hardware }
   }
 }
 
@@ -24,7 +45,9 @@ impl<'a> hal_spi::ErrorType for SpiDevice<'a> {
 
 impl<'a> hal_spi::SpiDevice for SpiDevice<'a> {
   async fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
-    self.cs.set_low();
+    if let ChipSelect::Gpio(cs) = &mut self.cs {
+      cs.set_low();
+    }
 
     let result = async {
       for op in operations {
@@ -40,7 +63,9 @@ impl<'a> hal_spi::SpiDevice for SpiDevice<'a> {
     }
     .await;
 
-    self.cs.set_high();
+    if let ChipSelect::Gpio(cs) = &mut self.cs {
+      cs.set_high();
+    }
     result
   }
 }
@@ -0,0 +1,25 @@
+/// Errors that can occur while receiving, verifying, or applying a firmware update.
+#[derive(Debug)]
+pub enum Error<F> {
+  /// Flash read/write/erase error (wraps the platform-specific `NorFlash` error type).
+  Flash(F),
+
+  /// The image or signature received doesn't fit the configured DFU partition/scratch buffer.
+  ImageTooLarge,
+
+  /// The image's Ed25519 signature didn't verify against the provisioned public key.
+  InvalidSignature,
+}
+
+// Manual defmt::Format impl — we can't derive it because the flash error type
+// may not implement Format. We log flash errors as "Flash error" and use
+// Debug for the rest.
+impl<F: core::fmt::Debug> defmt::Format for Error<F> {
+  fn format(&self, f: defmt::Formatter) {
+    match self {
+      Error::Flash(_) => defmt::write!(f, "Flash error"),
+      Error::ImageTooLarge => defmt::write!(f, "Image too large for DFU partition/scratch buffer"),
+      Error::InvalidSignature => defmt::write!(f, "Firmware image signature invalid"),
+    }
+  }
+}
@@ -0,0 +1,242 @@
+// Field firmware-update subsystem for deployed anchors/tags.
+//
+// Modeled on the usb-dfu + embassy-boot `FirmwareUpdater` flow: this crate
+// only owns writing the inactive ("DFU") flash bank and marking the swap
+// pending in the state partition. The actual bank swap happens in the
+// `bootloader` package on the next boot, same as embassy-boot's own split
+// between the application-side updater and the bootloader that applies it.
+//
+// Uses a type-state pattern, same as `dw3000::driver::Transceiver`, so the
+// compiler enforces that an image can't be trusted before it's verified:
+//
+//   Idle --erase()--> Receiving --write_chunk()-->* --verify()--> Verified
+//
+// Chunks can arrive over any `transport::UpdateTransport` — the existing
+// UWB link or a serial fallback.
+
+// `std` is only needed for `#[cfg(test)]` unit tests, which run on the host
+// target rather than the embedded one.
+#![cfg_attr(not(test), no_std)]
+
+pub mod error;
+pub mod transport;
+
+use embedded_storage_async::nor_flash::NorFlash;
+
+use crate::error::Error;
+
+/// Length of the Ed25519 signature appended after a firmware image.
+pub const SIGNATURE_LEN: usize = 64;
+
+/// Magic byte an embassy-boot-style bootloader looks for at the start of the
+/// state partition to know a bank swap is pending.
+const SWAP_MAGIC: u8 = 0xD0;
+
+/// Flash write granularity the state partition's magic must be aligned to
+/// (the nRF NVMC's, 4 bytes — `NorFlash::write` rejects anything narrower).
+/// embassy-boot repeats its magic byte across a whole word for the same
+/// reason we do here: a single stray byte can't land as a torn, ambiguous
+/// write, and the bootloader only recognizes the word-repeated form.
+const STATE_WRITE_SIZE: usize = 4;
+
+/// A flash region: byte offset and length within the flash device.
+#[derive(Clone, Copy)]
+pub struct Partition {
+  pub offset: u32,
+  pub len: u32,
+}
+
+// ── Type-state markers (zero-size) ──────────────────────────────────────────
+
+pub struct Idle;
+pub struct Receiving;
+pub struct Verified;
+
+/// An Ed25519 public key the device is provisioned with to verify incoming
+/// firmware images.
+#[derive(Clone, Copy)]
+pub struct PublicKey(salty::PublicKey);
+
+impl PublicKey {
+  /// Wrap a raw 32-byte public key, e.g. one burned into a compile-time table.
+  pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+    Self(salty::PublicKey::from_bytes(bytes))
+  }
+}
+
+/// Receives a new firmware image into the DFU bank, verifies it, and hands
+/// off to the bootloader.
+///
+/// Generic over `F`: any `embedded_storage_async::nor_flash::NorFlash`
+/// covering both `dfu` and `state` (a single `Mutex`-wrapped flash peripheral
+/// split by partition, as embassy-boot itself expects).
+pub struct FirmwareUpdater<F, STATE> {
+  flash: F,
+  dfu: Partition,
+  state: Partition,
+  written: u32,
+  _state: STATE,
+}
+
+impl<F: NorFlash> FirmwareUpdater<F, Idle> {
+  /// Wrap a flash device together with the DFU and state partition layout.
+  pub fn new(flash: F, dfu: Partition, state: Partition) -> Self {
+    Self { flash, dfu, state, written: 0, _state: Idle }
+  }
+
+  /// Erase the DFU bank so chunks can be written into it.
+  pub async fn erase(mut self) -> Result<FirmwareUpdater<F, Receiving>, Error<F::Error>> {
+    self.flash.erase(self.dfu.offset, self.dfu.offset + self.dfu.len).await.map_err(Error::Flash)?;
+    Ok(FirmwareUpdater { flash: self.flash, dfu: self.dfu, state: self.state, written: 0, _state: Receiving })
+  }
+}
+
+impl<F: NorFlash> FirmwareUpdater<F, Receiving> {
+  /// Write one chunk of the incoming image at `offset` bytes into the DFU bank.
+  pub async fn write_chunk(&mut self, offset: u32, data: &[u8]) -> Result<(), Error<F::Error>> {
+    if offset + data.len() as u32 > self.dfu.len {
+      return Err(Error::ImageTooLarge);
+    }
+    self.flash.write(self.dfu.offset + offset, data).await.map_err(Error::Flash)?;
+    self.written = self.written.max(offset + data.len() as u32);
+    Ok(())
+  }
+
+  /// Read the whole written image back through `scratch` and verify its
+  /// Ed25519 signature before trusting it.
+  ///
+  /// `image_len` is the image size without the trailing signature; `scratch`
+  /// must be at least `image_len` bytes.
+  pub async fn verify(
+    mut self,
+    image_len: u32,
+    scratch: &mut [u8],
+    signature: &[u8; SIGNATURE_LEN],
+    public_key: &PublicKey,
+  ) -> Result<FirmwareUpdater<F, Verified>, Error<F::Error>> {
+    let image_len = image_len as usize;
+    if image_len > scratch.len() || image_len as u32 > self.dfu.len {
+      return Err(Error::ImageTooLarge);
+    }
+
+    self.flash.read(self.dfu.offset, &mut scratch[..image_len]).await.map_err(Error::Flash)?;
+
+    let signature = salty::Signature::try_from(signature.as_slice()).map_err(|_| Error::InvalidSignature)?;
+    if public_key.0.verify(&scratch[..image_len], &signature).is_err() {
+      return Err(Error::InvalidSignature);
+    }
+
+    Ok(FirmwareUpdater { flash: self.flash, dfu: self.dfu, state: self.state, written: self.written, _state: Verified })
+  }
+}
+
+impl<F: NorFlash> FirmwareUpdater<F, Verified> {
+  /// Mark the DFU bank's swap as pending in the state partition, then reset
+  /// so the bootloader performs the bank swap on the next boot.
+  ///
+  /// Writes `SWAP_MAGIC` repeated across a `STATE_WRITE_SIZE`-aligned word
+  /// rather than a lone byte — a single-byte write both violates
+  /// `NorFlash::write`'s alignment requirement and wouldn't be recognized by
+  /// `bootloader`, which (like embassy-boot) expects its magic word-repeated.
+  pub async fn mark_pending_and_reset(mut self) -> Result<(), Error<F::Error>> {
+    let magic = [SWAP_MAGIC; STATE_WRITE_SIZE];
+    self.flash.write(self.state.offset, &magic).await.map_err(Error::Flash)?;
+    cortex_m::peripheral::SCB::sys_reset()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use embedded_storage_async::nor_flash::{ErrorType, NorFlash, NorFlashError, NorFlashErrorKind, ReadNorFlash};
+
+  use super::*;
+
+  /// Block on a future without pulling in an executor — these tests only
+  /// ever await the mock flash below, which never actually yields.
+  fn block_on<F: core::future::Future>(fut: F) -> F::Output {
+    use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+    fn noop(_: *const ()) {}
+    fn clone(_: *const ()) -> RawWaker {
+      RawWaker::new(core::ptr::null(), &VTABLE)
+    }
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    let waker = unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) };
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = core::pin::pin!(fut);
+    loop {
+      if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+        return out;
+      }
+    }
+  }
+
+  /// A `NorFlash` backed by a plain in-memory buffer, just big enough to
+  /// exercise `write_chunk`'s bounds checking without a real flash peripheral.
+  struct MockFlash(std::vec::Vec<u8>);
+
+  #[derive(Debug)]
+  struct MockFlashError;
+
+  impl NorFlashError for MockFlashError {
+    fn kind(&self) -> NorFlashErrorKind {
+      NorFlashErrorKind::Other
+    }
+  }
+
+  impl ErrorType for MockFlash {
+    type Error = MockFlashError;
+  }
+
+  impl ReadNorFlash for MockFlash {
+    const READ_SIZE: usize = 1;
+
+    async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+      let offset = offset as usize;
+      bytes.copy_from_slice(&self.0[offset..offset + bytes.len()]);
+      Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+      self.0.len()
+    }
+  }
+
+  impl NorFlash for MockFlash {
+    const WRITE_SIZE: usize = STATE_WRITE_SIZE;
+    const ERASE_SIZE: usize = STATE_WRITE_SIZE;
+
+    async fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+      self.0[from as usize..to as usize].fill(0xFF);
+      Ok(())
+    }
+
+    async fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+      let offset = offset as usize;
+      self.0[offset..offset + bytes.len()].copy_from_slice(bytes);
+      Ok(())
+    }
+  }
+
+  fn updater(dfu_len: u32) -> FirmwareUpdater<MockFlash, Receiving> {
+    let flash = MockFlash(std::vec![0xFFu8; dfu_len as usize + 16]);
+    let dfu = Partition { offset: 0, len: dfu_len };
+    let state = Partition { offset: dfu_len, len: 16 };
+    FirmwareUpdater { flash, dfu, state, written: 0, _state: Receiving }
+  }
+
+  #[test]
+  fn write_chunk_accepts_data_within_bounds() {
+    let mut updater = updater(64);
+    assert!(block_on(updater.write_chunk(0, &[1, 2, 3, 4])).is_ok());
+    assert_eq!(updater.written, 4);
+  }
+
+  #[test]
+  fn write_chunk_rejects_data_past_dfu_bank() {
+    let mut updater = updater(64);
+    let err = block_on(updater.write_chunk(60, &[1, 2, 3, 4, 5])).unwrap_err();
+    assert!(matches!(err, Error::ImageTooLarge));
+  }
+}
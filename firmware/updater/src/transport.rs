@@ -0,0 +1,45 @@
+// Chunk-reception transports for the firmware updater.
+//
+// An update is normally pushed over the existing UWB ranging link — no need
+// to physically reach an anchor mounted in the arena ceiling — but a serial
+// fallback lets a device be recovered on the bench if it isn't answering on
+// the radio (e.g. a bad image already flashed).
+
+use embedded_hal_async::digital::Wait;
+use embedded_hal_async::spi::SpiDevice;
+
+use dw3000::chip::Chip;
+use dw3000::driver::{Ready, Transceiver};
+
+/// How many SYS_STATUS polls a single chunk receive is allowed before giving
+/// up, when the UWB transport is used without an IRQ pin. See
+/// `Transceiver::recv`.
+const UWB_CHUNK_MAX_POLLS: u32 = 10_000;
+
+/// Something the firmware updater can pull image chunks from.
+pub trait UpdateTransport {
+  type Error;
+
+  /// Receive the next chunk into `buf`, returning the number of bytes read.
+  async fn recv_chunk(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error>;
+}
+
+/// Receive update chunks over the existing UWB ranging link.
+impl<SPI: SpiDevice, CHIP: Chip, IRQ: Wait> UpdateTransport for Transceiver<SPI, CHIP, Ready, IRQ> {
+  type Error = dw3000::Error<SPI::Error>;
+
+  async fn recv_chunk(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+    self.recv(buf, UWB_CHUNK_MAX_POLLS).await
+  }
+}
+
+/// Receive update chunks over a serial fallback link.
+pub struct Serial<S>(pub S);
+
+impl<S: embedded_io_async::Read> UpdateTransport for Serial<S> {
+  type Error = S::Error;
+
+  async fn recv_chunk(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+    self.0.read(buf).await
+  }
+}
@@ -3,6 +3,7 @@ use std::process::{Command, exit};
 const FIRMWARE_TARGET: &str = "thumbv7em-none-eabihf";
 const FIRMWARE_PACKAGES: &[&str] = &["tag", "anchor"];
 const NATIVE_PACKAGES: &[&str] = &["server"];
+const BOOTLOADER_PACKAGE: &str = "bootloader";
 
 fn cargo(args: &[&str]) {
   let status = Command::new("cargo").args(args).status().expect("failed to run cargo");
@@ -25,8 +26,44 @@ fn build_native() {
   }
 }
 
+fn build_bootloader() {
+  println!("Building bootloader");
+  cargo(&["build", "-p", BOOTLOADER_PACKAGE, "--target", FIRMWARE_TARGET, "--release"]);
+}
+
+/// Build a release image for `package` and flash it directly to the active
+/// bank via probe-rs, for bench iteration.
+///
+/// This is a debug convenience, not the field-update path: it flashes the
+/// ELF at its linked (active-bank) addresses, not the DFU partition, and it
+/// doesn't sign anything. A real field update goes through the device's own
+/// `updater`/`bootloader` flow instead — receive a signed image over the
+/// transport, verify its Ed25519 signature, then let `bootloader` swap
+/// banks — see the `updater` package.
+fn flash_update(package: &str) {
+  if !FIRMWARE_PACKAGES.contains(&package) {
+    eprintln!("Unknown firmware package for flash-update: {package}");
+    eprintln!("Expected one of: {FIRMWARE_PACKAGES:?}");
+    exit(1);
+  }
+
+  println!("Building: {package}");
+  cargo(&["build", "-p", package, "--target", FIRMWARE_TARGET, "--release"]);
+
+  let elf_path = format!("target/{FIRMWARE_TARGET}/release/{package}");
+  println!("Flashing {package} to the active bank via probe-rs (bench-only — not a signed DFU push)");
+  let status = Command::new("probe-rs")
+    .args(["download", "--chip", "nRF52840_xxAA", "--binary-format", "elf", &elf_path])
+    .status()
+    .expect("failed to run probe-rs (is it installed?)");
+  if !status.success() {
+    exit(status.code().unwrap_or(1));
+  }
+}
+
 fn main() {
-  let task = std::env::args().nth(1);
+  let mut args = std::env::args().skip(1);
+  let task = args.next();
   match task.as_deref() {
     Some("build") | None => {
       build_firmware();
@@ -34,9 +71,17 @@ fn main() {
     }
     Some("build-firmware") => build_firmware(),
     Some("build-server") => build_native(),
+    Some("build-bootloader") => build_bootloader(),
+    Some("flash-update") => {
+      let Some(package) = args.next() else {
+        eprintln!("Usage: xtask flash-update <tag|anchor>");
+        exit(1);
+      };
+      flash_update(&package);
+    }
     Some(unknown) => {
       eprintln!("Unknown task: {unknown}");
-      eprintln!("Available tasks: build, build-firmware, build-server");
+      eprintln!("Available tasks: build, build-firmware, build-server, build-bootloader, flash-update <package>");
       exit(1);
     }
   }